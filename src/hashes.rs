@@ -3,37 +3,58 @@ use std::fs;
 
 use smash_arc::Hash40;
 
+/// Community label file shipped alongside Skyline
+const HASHES_PATH: &str = "rom:/skyline/hashes.txt";
+/// Labels added by the user, loaded on top of the community ones
+const USER_HASHES_PATH: &str = "rom:/arcropolis/hashes.txt";
+
 lazy_static::lazy_static! {
     static ref HASHES : HashMap<Hash40, &'static str> = {
         let mut hashes = HashMap::default();
 
-        let str_path = "rom:/skyline/hashes.txt";
+        load_labels(&mut hashes, HASHES_PATH);
+        load_labels(&mut hashes, USER_HASHES_PATH);
+
+        return hashes;
+    };
+}
 
-        let s = match fs::read_to_string(str_path){
-            Err(why) =>  {
-                println!("[HashesMgr] Failed to read \"{}\" \"({})\"", str_path, why);
-                return hashes;
-            },
-            Ok(s) => s
-        };
+fn load_labels(hashes: &mut HashMap<Hash40, &'static str>, str_path: &str) {
+    let s = match fs::read_to_string(str_path){
+        Err(why) =>  {
+            println!("[HashesMgr] Failed to read \"{}\" \"({})\"", str_path, why);
+            return;
+        },
+        Ok(s) => s
+    };
 
-        for hs in string_to_static_str(s).lines() {
-            hashes.insert(Hash40::from(hs), hs);
+    for hs in string_to_static_str(s).lines() {
+        let hs = hs.trim();
+
+        if hs.is_empty() {
+            continue;
         }
 
-        return hashes;
-    };
+        hashes.insert(Hash40::from(hs), hs);
+    }
 }
 
 pub fn string_to_static_str(s: String) -> &'static str {
     Box::leak(s.into_boxed_str())
 }
 
-#[allow(dead_code)]
 pub fn get(x: Hash40) -> Option<&'static &'static str> {
     HASHES.get(&x)
 }
 
+/// Get the label for a hash, or its raw hexadecimal value if it is unknown
+pub fn get_label(x: Hash40) -> String {
+    match get(x) {
+        Some(label) => label.to_string(),
+        None => format!("{:#012x}", x.as_u64()),
+    }
+}
+
 pub fn init() {
     lazy_static::initialize(&HASHES);
 }
@@ -87,7 +87,7 @@ fn replace_file_by_index(file_index: FileIndex) {
 
         let file_slice = file_ctx.get_file_content().into_boxed_slice();
 
-        info!("[ResInflateThread | #{}] Replacing '{}'", usize::from(file_ctx.index).green(), hashes::get(file_ctx.file.hash40().unwrap()).unwrap_or(&"Unknown").bright_yellow());
+        info!("[ResInflateThread | #{}] Replacing '{}'", usize::from(file_ctx.index).green(), hashes::get_label(file_ctx.file.hash40().unwrap()).bright_yellow());
 
         unsafe {
             let mut data_slice = std::slice::from_raw_parts_mut(table2entry.data as *mut u8, file_slice.len());
@@ -102,7 +102,7 @@ fn replace_textures_by_index(file_ctx: &FileCtx, table2entry: &mut Table2Entry)
 
     let file_slice = file_ctx.get_file_content().into_boxed_slice();
 
-    info!("[ResInflateThread | #{}] Replacing '{}'", usize::from(file_ctx.index).green(), hashes::get(file_ctx.file.hash40().unwrap()).unwrap_or(&"Unknown").bright_yellow());
+    info!("[ResInflateThread | #{}] Replacing '{}'", usize::from(file_ctx.index).green(), hashes::get_label(file_ctx.file.hash40().unwrap()).bright_yellow());
 
     if orig_size > file_slice.len() {
         let data_slice = unsafe { std::slice::from_raw_parts_mut(table2entry.data as *mut u8, orig_size) };
@@ -130,7 +130,7 @@ fn inflate_incoming(ctx: &InlineCtx) {
 
         let hash = arc.get_file_paths()[path_idx].path.hash40();
 
-        info!("[ResInflateThread | #{}] Incoming '{}'", path_idx.green(), hashes::get(hash).unwrap_or(&"Unknown").bright_yellow());
+        info!("[ResInflateThread | #{}] Incoming '{}'", path_idx.green(), hashes::get_label(hash).bright_yellow());
 
         let mut incoming = INCOMING_IDX.write();
 
@@ -151,7 +151,7 @@ fn loading_incoming(ctx: &InlineCtx) {
         let path_idx = *ctx.registers[25].x.as_ref() as u32;
         let hash = arc.get_file_paths()[path_idx as usize].path.hash40();
 
-        info!("[ResLoadingThread | #{}] Incoming '{}'", path_idx.bright_yellow(), hashes::get(hash).unwrap_or(&"Unknown").bright_yellow());
+        info!("[ResLoadingThread | #{}] Incoming '{}'", path_idx.bright_yellow(), hashes::get_label(hash).bright_yellow());
     }
 }
 
@@ -291,7 +291,7 @@ fn initial_loading(_ctx: &InlineCtx) {
 
 #[skyline::main(name = "arcropolis")]
 pub fn main() {
-    // Load hashes from rom:/skyline/hashes.txt if the file is present
+    // Load hashes from rom:/skyline/hashes.txt and rom:/arcropolis/hashes.txt if the files are present
     hashes::init();
     // Look for the offset of the various functions to hook
     offsets::search_offsets();